@@ -0,0 +1,140 @@
+use std::sync::{Arc, OnceLock};
+
+use reqwest::header::AUTHORIZATION;
+
+use crate::Result;
+
+// The default API host for github.com, as opposed to a [Github Enterprise]
+// instance.
+//
+// [Github Enterprise]: https://docs.github.com/en/enterprise-server
+const DEFAULT_HOST: &str = "https://api.github.com";
+
+/// Holds the credentials and host needed to talk to a [Github] API.
+///
+/// Every request this crate makes goes through a `GithubClient`, so a
+/// personal access token (and, for [Github Enterprise] users, a custom
+/// `host`) only has to be set up once and is shared by every call instead
+/// of hitting the API anonymously.
+///
+/// Holds both a blocking and an async `reqwest` client, so the same
+/// `GithubClient` backs whichever API surface the caller picks: the
+/// blocking methods (e.g. [`Repo::new`](crate::Repo::new)) or their
+/// `_async` counterparts.
+///
+/// The blocking client isn't built until the first blocking call actually
+/// needs it: `reqwest::blocking::Client::new` panics if it's constructed
+/// while a Tokio runtime is already driving the current thread, which would
+/// otherwise make `GithubClient::new()` unusable from inside `async fn
+/// main` just to call `Repo::new_async`.
+///
+/// # Example
+///
+/// ```
+/// use github_stats::GithubClient;
+///
+/// let client = GithubClient::new().token("a1b2c3");
+/// ```
+///
+/// [Github]: https://github.com/
+/// [Github Enterprise]: https://docs.github.com/en/enterprise-server
+#[derive(Debug, Clone)]
+pub struct GithubClient {
+    token: Option<String>,
+    host: String,
+    client: Arc<OnceLock<reqwest::blocking::Client>>,
+    async_client: reqwest::Client,
+}
+
+impl Default for GithubClient {
+    fn default() -> Self {
+        GithubClient {
+            token: None,
+            host: String::from(DEFAULT_HOST),
+            client: Arc::new(OnceLock::new()),
+            async_client: reqwest::Client::new(),
+        }
+    }
+}
+
+impl GithubClient {
+    /// Creates a new, unauthenticated `GithubClient` pointed at
+    /// `https://api.github.com`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the personal access token used to authenticate requests.
+    pub fn token<T: Into<String>>(mut self, token: T) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Points this client at a [Github Enterprise] host instead of
+    /// `https://api.github.com`.
+    ///
+    /// [Github Enterprise]: https://docs.github.com/en/enterprise-server
+    pub fn host<H: Into<String>>(mut self, host: H) -> Self {
+        self.host = host.into();
+        self
+    }
+
+    /// The API host this client is configured to talk to.
+    pub fn api_host(&self) -> &str {
+        &self.host
+    }
+
+    /// Issues a `GET` request against `url`, attaching the
+    /// `Authorization: token …` header when a token is set.
+    pub(crate) fn get(&self, url: &str) -> Result<reqwest::blocking::Response> {
+        let client = self.client.get_or_init(reqwest::blocking::Client::new);
+        let mut request = client.get(url);
+        if let Some(token) = &self.token {
+            request = request.header(AUTHORIZATION, format!("token {}", token));
+        }
+        Ok(request.send()?)
+    }
+
+    /// Async counterpart to [`get`](Self::get), built on `reqwest`'s
+    /// non-blocking client.
+    pub(crate) async fn get_async(&self, url: &str) -> Result<reqwest::Response> {
+        let mut request = self.async_client.get(url);
+        if let Some(token) = &self.token {
+            request = request.header(AUTHORIZATION, format!("token {}", token));
+        }
+        Ok(request.send().await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_the_github_dot_com_host() {
+        let client = GithubClient::new();
+
+        assert_eq!(DEFAULT_HOST, client.api_host());
+    }
+
+    #[test]
+    fn host_overrides_the_default() {
+        let client = GithubClient::new().host("https://github.example.com/api/v3");
+
+        assert_eq!("https://github.example.com/api/v3", client.api_host());
+    }
+
+    #[test]
+    fn setting_a_token_does_not_affect_the_host() {
+        let client = GithubClient::new().token("a1b2c3");
+
+        assert_eq!(DEFAULT_HOST, client.api_host());
+    }
+
+    #[test]
+    fn blocking_client_is_not_built_until_the_first_blocking_call() {
+        let client = GithubClient::new();
+
+        assert!(client.client.get().is_none());
+    }
+}
@@ -0,0 +1,31 @@
+//! A small client for pulling stats about a [Github] repository: stars,
+//! forks, issues, languages, and releases.
+//!
+//! [Github]: https://github.com/
+
+use std::collections::HashMap;
+
+pub use client::GithubClient;
+pub use repository::{Contributor, Issue, LanguageStat, Release, Repo, State};
+pub use search::Query;
+
+mod client;
+mod repository;
+mod search;
+
+/// A JSON value as returned by the [Github] API.
+///
+/// [Github]: https://github.com/
+pub type Response = serde_json::Value;
+
+/// A map from a [Github]-provided key (e.g. a language name) to a value
+/// parsed out of the API response.
+///
+/// [Github]: https://github.com/
+pub type JsonMap<V> = HashMap<String, V>;
+
+/// This crate's general error type.
+pub type Error = Box<dyn std::error::Error>;
+
+/// This crate's general result type.
+pub type Result<T> = std::result::Result<T, Error>;
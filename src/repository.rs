@@ -1,32 +1,28 @@
 use big_bytes::BigByte;
+use futures::try_join;
 
-use crate::{JsonMap, Response, Result};
+use crate::{GithubClient, JsonMap, Response, Result};
 
-use issues::issue_stats;
+use issues::{issue_stats, issue_stats_async};
+
+pub use contributors::Contributor;
+pub use issues::{Issue, State};
+pub use languages::LanguageStat;
 pub use releases::Release;
 
+mod commits;
+pub mod contributors;
 mod issues;
 pub mod languages;
+mod pagination;
 pub mod releases;
 
-// The URL for [Github] repository data.
-//
-// Append `/`*user*`/`*repo* to the end for the full URL.
-//
-// # Example
-//
-// ```
-// let url = format!("{}/{}/{}", GITHUB_API_REPO_URL, "rust-lang", "rust");
-// ```
-//
-// [Github]: https://github.com/
-const GITHUB_API_REPO_URL: &str = "https://api.github.com/repos";
-
 /// Represents that stats of a [Github] repository.
 ///
 /// [Github]: https://github.com/
 #[derive(Debug)]
 pub struct Repo {
+    owner: String,
     name: String,
     created: String,
     updated: String,
@@ -42,6 +38,8 @@ pub struct Repo {
     closed_pull_requests: u64,
     latest_release: Option<Release>,
     is_fork: bool,
+    commit_count: u64,
+    last_commit_date: Option<String>,
 }
 
 impl Repo {
@@ -50,12 +48,64 @@ impl Repo {
     /// # Example
     ///
     /// ```
-    /// use github_stats::Repo;
+    /// use github_stats::{GithubClient, Repo};
     ///
-    /// let repo = Repo::new("rust-lang", "rust");
+    /// let client = GithubClient::new().token("a1b2c3");
+    /// let repo = Repo::new(&client, "rust-lang", "rust");
     /// ```
-    pub fn new(user: &str, repo: &str) -> Result<Self> {
-        let repo_data = repo_stats(user, repo)?;
+    pub fn new(client: &GithubClient, user: &str, repo: &str) -> Result<Self> {
+        let repo_data = repo_stats(client, user, repo)?;
+        let languages = languages::from_api_url(client, languages_url(&repo_data)?)?;
+        let issue_counts = issue_stats(client, user, repo)?;
+        let latest_release = Release::latest(client, user, repo)?;
+        let commit_stats = commits::stats(client, user, repo)?;
+
+        Self::from_parts(
+            user,
+            repo_data,
+            languages,
+            issue_counts,
+            latest_release,
+            commit_stats,
+        )
+    }
+
+    /// Async counterpart to [`new`](Self::new), built on `reqwest`'s async
+    /// client. The languages, issue/pull request counts, latest release,
+    /// and commit stats are independent of one another, so they're fetched
+    /// concurrently instead of one round trip at a time.
+    pub async fn new_async(client: &GithubClient, user: &str, repo: &str) -> Result<Self> {
+        let repo_data = repo_stats_async(client, user, repo).await?;
+        let languages_url = languages_url(&repo_data)?.to_string();
+
+        let (languages, issue_counts, latest_release, commit_stats) = try_join!(
+            languages::from_api_url_async(client, &languages_url),
+            issue_stats_async(client, user, repo),
+            Release::latest_async(client, user, repo),
+            commits::stats_async(client, user, repo),
+        )?;
+
+        Self::from_parts(
+            user,
+            repo_data,
+            languages,
+            issue_counts,
+            latest_release,
+            commit_stats,
+        )
+    }
+
+    // Assembles a `Repo` out of the repo endpoint's raw JSON together with
+    // the data pulled from the languages, issues, releases, and commits
+    // endpoints, shared by both the blocking and async constructors.
+    fn from_parts(
+        user: &str,
+        repo_data: Response,
+        languages: JsonMap<u64>,
+        issue_counts: (u64, u64, u64, u64),
+        latest_release: Option<Release>,
+        commit_stats: (u64, Option<String>),
+    ) -> Result<Self> {
         let name = repo_data["name"]
             .as_str()
             .ok_or(r#""name" is not a string"#)?
@@ -72,11 +122,6 @@ impl Repo {
             .as_str()
             .ok_or(r#""language" is not a string"#)?
             .to_string();
-        let languages = languages::from_api_url(
-            repo_data["languages_url"]
-            .as_str()
-            .ok_or(r#""languages_url" is not a string"#)?
-        )?;
         let homepage = match repo_data["homepage"].as_str() {
             None | Some("") => None,
             Some(s) => Some(String::from(s)),
@@ -91,14 +136,14 @@ impl Repo {
         let forks = repo_data["forks"]
             .as_u64()
             .ok_or(r#""forks_count" cannot be read as u64"#)?;
-        let (open_issues, closed_issues, open_pull_requests, closed_pull_requests) =
-            issue_stats(user, repo)?;
+        let (open_issues, closed_issues, open_pull_requests, closed_pull_requests) = issue_counts;
         let is_fork = repo_data["fork"]
             .as_bool()
             .ok_or(r#""fork" could not be read as bool"#)?;
-        let latest_release = Release::latest(user, repo)?;
+        let (commit_count, last_commit_date) = commit_stats;
 
         let repo = Repo {
+            owner: String::from(user),
             name,
             created,
             updated,
@@ -114,15 +159,27 @@ impl Repo {
             closed_pull_requests,
             latest_release,
             is_fork,
+            commit_count,
+            last_commit_date,
         };
         Ok(repo)
     }
 
+    /// Gets the repository's owner.
+    pub fn owner(&self) -> &str {
+        &self.owner
+    }
+
     /// Gets the repository's name.
     pub fn name(&self) -> &str {
         &self.name
     }
 
+    /// The repository's full name, e.g. `rust-lang/rust`.
+    pub fn full_name(&self) -> String {
+        format!("{}/{}", self.owner, self.name)
+    }
+
     /// Gets the repository's creation date.
     pub fn created(&self) -> &str {
         &self.created
@@ -145,6 +202,14 @@ impl Repo {
         &self.languages
     }
 
+    /// Each language's share of this repository's code, as a percentage,
+    /// alongside the color [Github] renders it with.
+    ///
+    /// [Github]: https://github.com/
+    pub fn language_stats(&self) -> Vec<LanguageStat> {
+        languages::stats(&self.languages)
+    }
+
     /// The repository's homepage, if it exists.
     pub fn homepage(&self) -> &Option<String> {
         &self.homepage
@@ -192,6 +257,18 @@ impl Repo {
         self.closed_pull_requests
     }
 
+    /// Fetches every issue/pull request in the given `state`, paging
+    /// through Github's `Link: rel="next"` header until there's nothing
+    /// left to fetch.
+    pub fn issues(&self, client: &GithubClient, state: State) -> Result<Vec<Issue>> {
+        issues::list(client, &self.owner, &self.name, state)
+    }
+
+    /// Async counterpart to [`issues`](Self::issues).
+    pub async fn issues_async(&self, client: &GithubClient, state: State) -> Result<Vec<Issue>> {
+        issues::list_async(client, &self.owner, &self.name, state).await
+    }
+
     /// The latest release.
     pub fn latest_release(&self) -> &Option<Release> {
         &self.latest_release
@@ -201,30 +278,75 @@ impl Repo {
     pub fn is_fork(&self) -> bool {
         self.is_fork
     }
+
+    /// The repository's total commit count.
+    pub fn commit_count(&self) -> u64 {
+        self.commit_count
+    }
+
+    /// When the most recent commit was made, if the repository has any
+    /// commits.
+    pub fn last_commit_date(&self) -> &Option<String> {
+        &self.last_commit_date
+    }
+
+    /// Fetches every contributor to this repository, paging through
+    /// Github's `Link: rel="next"` header until there's nothing left to
+    /// fetch.
+    pub fn contributors(&self, client: &GithubClient) -> Result<Vec<Contributor>> {
+        contributors::list(client, &self.owner, &self.name)
+    }
+
+    /// Async counterpart to [`contributors`](Self::contributors).
+    pub async fn contributors_async(&self, client: &GithubClient) -> Result<Vec<Contributor>> {
+        contributors::list_async(client, &self.owner, &self.name).await
+    }
 }
 
 // Takes [Github] user and repo IDs to make a link to the API for that repo.
 //
 // [Github]: https://github.com/
-fn repo_api_url(user: &str, repo: &str) -> String {
-    format!("{}/{}/{}", GITHUB_API_REPO_URL, user, repo)
+fn repo_api_url(client: &GithubClient, user: &str, repo: &str) -> String {
+    format!("{}/repos/{}/{}", client.api_host(), user, repo)
 }
 
 // Requests repo data from [Github]'s API.
 //
 // [Github]: https://github.com/
-fn repo_stats(user: &str, repo: &str) -> Result<Response> {
-    let response: Response = reqwest::get(&repo_api_url(user, repo))?.json()?;
+fn repo_stats(client: &GithubClient, user: &str, repo: &str) -> Result<Response> {
+    let response: Response = client.get(&repo_api_url(client, user, repo))?.json()?;
     Ok(response)
 }
 
+// Async counterpart to [`repo_stats`].
+//
+// [Github]: https://github.com/
+async fn repo_stats_async(client: &GithubClient, user: &str, repo: &str) -> Result<Response> {
+    let response: Response = client
+        .get_async(&repo_api_url(client, user, repo))
+        .await?
+        .json()
+        .await?;
+    Ok(response)
+}
+
+// Pulls the `languages_url` a repo's raw JSON points to, the source used by
+// both [`Repo::new`] and [`Repo::new_async`] to fetch its language
+// breakdown.
+fn languages_url(repo_data: &Response) -> Result<&str> {
+    repo_data["languages_url"]
+        .as_str()
+        .ok_or(r#""languages_url" is not a string"#.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn it_works() {
-        println!("{:#?}", repo_stats("rust-lang", "rust").unwrap());
+        let client = GithubClient::new();
+        println!("{:#?}", repo_stats(&client, "rust-lang", "rust").unwrap());
         assert!(true);
     }
 }
\ No newline at end of file
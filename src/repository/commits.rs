@@ -0,0 +1,134 @@
+use reqwest::header::{HeaderMap, LINK};
+
+use crate::{GithubClient, Response, Result};
+
+/// Fetches the repo's total commit count and the date of its most recent
+/// commit.
+///
+/// Github's commits endpoint doesn't expose a total count directly, so this
+/// requests a single commit per page and reads the page number out of the
+/// `Link: rel="last"` header, which is the true number of commits.
+///
+/// [Github]: https://github.com/
+pub fn stats(client: &GithubClient, user: &str, repo: &str) -> Result<(u64, Option<String>)> {
+    let response = client.get(&commits_url(client, user, repo))?;
+    if response.status() == reqwest::StatusCode::CONFLICT {
+        // A brand-new or genuinely empty repo has no commits to list, and
+        // Github's commits endpoint signals that with a 409 rather than `[]`.
+        return Ok((0, None));
+    }
+
+    let headers = response.headers().clone();
+    let commits: Vec<Response> = response.json()?;
+    Ok(from_first_page(&headers, &commits))
+}
+
+/// Async counterpart to [`stats`].
+///
+/// [Github]: https://github.com/
+pub async fn stats_async(
+    client: &GithubClient,
+    user: &str,
+    repo: &str,
+) -> Result<(u64, Option<String>)> {
+    let response = client.get_async(&commits_url(client, user, repo)).await?;
+    if response.status() == reqwest::StatusCode::CONFLICT {
+        return Ok((0, None));
+    }
+
+    let headers = response.headers().clone();
+    let commits: Vec<Response> = response.json().await?;
+    Ok(from_first_page(&headers, &commits))
+}
+
+fn from_first_page(headers: &HeaderMap, commits: &[Response]) -> (u64, Option<String>) {
+    let last_commit_date = commits
+        .first()
+        .and_then(|commit| commit["commit"]["author"]["date"].as_str())
+        .map(String::from);
+    let commit_count = last_page_number(headers).unwrap_or(commits.len() as u64);
+
+    (commit_count, last_commit_date)
+}
+
+// Builds the URL for the first page of a repo's commits, one commit per
+// page so the `Link: rel="last"` header's page number is the total commit
+// count.
+fn commits_url(client: &GithubClient, user: &str, repo: &str) -> String {
+    format!(
+        "{}/repos/{}/{}/commits?per_page=1",
+        client.api_host(),
+        user,
+        repo,
+    )
+}
+
+// Parses the page number out of the `<url>; rel="last"` pagination link in
+// a Github `Link` response header, if one is present.
+fn last_page_number(headers: &HeaderMap) -> Option<u64> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+    link.split(',').find_map(|entry| {
+        let mut parts = entry.split(';');
+        let url = parts.next()?.trim();
+        let is_last = parts.any(|param| param.trim() == r#"rel="last""#);
+        if !is_last {
+            return None;
+        }
+        let url = url.trim_start_matches('<').trim_end_matches('>');
+        let query = url.split('?').nth(1)?;
+        query.split('&').find_map(|pair| {
+            let mut pair = pair.splitn(2, '=');
+            if pair.next()? == "page" {
+                pair.next()?.parse().ok()
+            } else {
+                None
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn reads_commit_count_from_last_page_link_and_date_from_first_commit() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(
+                r#"<https://api.github.com/repos/o/r/commits?per_page=1&page=2>; rel="next", <https://api.github.com/repos/o/r/commits?per_page=1&page=418>; rel="last""#,
+            ),
+        );
+        let commits = vec![json!({
+            "commit": {"author": {"date": "2020-06-15T00:00:00Z"}},
+        })];
+
+        let (commit_count, last_commit_date) = from_first_page(&headers, &commits);
+
+        assert_eq!(418, commit_count);
+        assert_eq!(Some(String::from("2020-06-15T00:00:00Z")), last_commit_date);
+    }
+
+    #[test]
+    fn falls_back_to_page_length_without_a_last_page_link() {
+        let commits = vec![json!({
+            "commit": {"author": {"date": "2020-06-15T00:00:00Z"}},
+        })];
+
+        let (commit_count, _) = from_first_page(&HeaderMap::new(), &commits);
+
+        assert_eq!(1, commit_count);
+    }
+
+    #[test]
+    fn empty_page_yields_no_commits_and_no_date() {
+        let (commit_count, last_commit_date) = from_first_page(&HeaderMap::new(), &[]);
+
+        assert_eq!(0, commit_count);
+        assert_eq!(None, last_commit_date);
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{GithubClient, Response, Result};
+
+use super::pagination::next_page_url;
+
+/// A single contributor to a [Github] repository, as returned by the
+/// contributors endpoint.
+///
+/// [Github]: https://github.com/
+#[derive(Debug)]
+pub struct Contributor {
+    login: String,
+    contributions: u64,
+}
+
+impl Contributor {
+    fn from_response(item: &Response) -> Result<Self> {
+        let login = item["login"]
+            .as_str()
+            .ok_or(r#""login" is not a string"#)?
+            .to_string();
+        let contributions = item["contributions"]
+            .as_u64()
+            .ok_or(r#""contributions" is not a u64"#)?;
+
+        Ok(Contributor {
+            login,
+            contributions,
+        })
+    }
+
+    /// The contributor's [Github] login.
+    ///
+    /// [Github]: https://github.com/
+    pub fn login(&self) -> &str {
+        &self.login
+    }
+
+    /// The number of commits this contributor has made.
+    pub fn contributions(&self) -> u64 {
+        self.contributions
+    }
+}
+
+// Fetches every contributor for a repo, following Github's
+// `Link: rel="next"` pagination header until it's exhausted.
+//
+// [Github]: https://github.com/
+pub fn list(client: &GithubClient, user: &str, repo: &str) -> Result<Vec<Contributor>> {
+    let mut next_url = Some(contributors_url(client, user, repo));
+    let mut contributors = Vec::new();
+
+    while let Some(url) = next_url {
+        let response = client.get(&url)?;
+        next_url = next_page_url(response.headers());
+        let page: Vec<Response> = response.json()?;
+        for item in &page {
+            contributors.push(Contributor::from_response(item)?);
+        }
+    }
+
+    Ok(contributors)
+}
+
+// Async counterpart to [`list`].
+//
+// [Github]: https://github.com/
+pub async fn list_async(
+    client: &GithubClient,
+    user: &str,
+    repo: &str,
+) -> Result<Vec<Contributor>> {
+    let mut next_url = Some(contributors_url(client, user, repo));
+    let mut contributors = Vec::new();
+
+    while let Some(url) = next_url {
+        let response = client.get_async(&url).await?;
+        next_url = next_page_url(response.headers());
+        let page: Vec<Response> = response.json().await?;
+        for item in &page {
+            contributors.push(Contributor::from_response(item)?);
+        }
+    }
+
+    Ok(contributors)
+}
+
+// Builds the first page URL for listing a repo's contributors.
+fn contributors_url(client: &GithubClient, user: &str, repo: &str) -> String {
+    format!(
+        "{}/repos/{}/{}/contributors?per_page=100",
+        client.api_host(),
+        user,
+        repo,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_contributor_from_response() {
+        let item = json!({
+            "login": "octocat",
+            "contributions": 1234,
+        });
+
+        let contributor = Contributor::from_response(&item).unwrap();
+
+        assert_eq!("octocat", contributor.login());
+        assert_eq!(1234, contributor.contributions());
+    }
+}
@@ -0,0 +1,351 @@
+use futures::try_join;
+
+use crate::{GithubClient, Response, Result};
+
+use super::pagination::next_page_url;
+
+// The path of [Github]'s issue/PR search endpoint, relative to the API host.
+//
+// [Github]: https://github.com/
+const GITHUB_SEARCH_ISSUES_URL: &str = "/search/issues";
+
+/// Whether to fetch open, closed, or all issues/pull requests.
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    Open,
+    Closed,
+    All,
+}
+
+impl State {
+    fn as_str(self) -> &'static str {
+        match self {
+            State::Open => "open",
+            State::Closed => "closed",
+            State::All => "all",
+        }
+    }
+}
+
+/// A single issue or pull request, as returned by [Github]'s issues
+/// endpoint.
+///
+/// [Github]: https://github.com/
+#[derive(Debug)]
+pub struct Issue {
+    number: u64,
+    title: String,
+    state: String,
+    created_at: String,
+    updated_at: String,
+    labels: Vec<String>,
+    is_pull_request: bool,
+}
+
+impl Issue {
+    fn from_response(item: &Response) -> Result<Self> {
+        let number = item["number"]
+            .as_u64()
+            .ok_or(r#""number" is not a u64"#)?;
+        let title = item["title"]
+            .as_str()
+            .ok_or(r#""title" is not a string"#)?
+            .to_string();
+        let state = item["state"]
+            .as_str()
+            .ok_or(r#""state" is not a string"#)?
+            .to_string();
+        let created_at = item["created_at"]
+            .as_str()
+            .ok_or(r#""created_at" is not a string"#)?
+            .to_string();
+        let updated_at = item["updated_at"]
+            .as_str()
+            .ok_or(r#""updated_at" is not a string"#)?
+            .to_string();
+        let labels = item["labels"]
+            .as_array()
+            .ok_or(r#""labels" is not an array"#)?
+            .iter()
+            .map(|label| {
+                label["name"]
+                    .as_str()
+                    .ok_or(r#""name" is not a string"#)
+                    .map(String::from)
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let is_pull_request = !item["pull_request"].is_null();
+
+        Ok(Issue {
+            number,
+            title,
+            state,
+            created_at,
+            updated_at,
+            labels,
+            is_pull_request,
+        })
+    }
+
+    /// The issue or pull request number.
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+
+    /// The issue or pull request title.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The issue or pull request's state, e.g. `open` or `closed`.
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// When the issue or pull request was created.
+    pub fn created_at(&self) -> &str {
+        &self.created_at
+    }
+
+    /// When the issue or pull request was last updated.
+    pub fn updated_at(&self) -> &str {
+        &self.updated_at
+    }
+
+    /// The names of the labels attached to this issue or pull request.
+    pub fn labels(&self) -> &[String] {
+        &self.labels
+    }
+
+    /// [Github] exposes pull requests through the issues endpoint; this is
+    /// `true` when this entry is actually a pull request.
+    ///
+    /// [Github]: https://github.com/
+    pub fn is_pull_request(&self) -> bool {
+        self.is_pull_request
+    }
+}
+
+// Counts open/closed issues and pull requests for a repo via the search API,
+// since the repo endpoint itself doesn't break that total down.
+//
+// [Github]: https://github.com/
+pub fn issue_stats(
+    client: &GithubClient,
+    user: &str,
+    repo: &str,
+) -> Result<(u64, u64, u64, u64)> {
+    let open_issues = count_for(client, user, repo, "issue", "open")?;
+    let closed_issues = count_for(client, user, repo, "issue", "closed")?;
+    let open_pull_requests = count_for(client, user, repo, "pr", "open")?;
+    let closed_pull_requests = count_for(client, user, repo, "pr", "closed")?;
+
+    Ok((
+        open_issues,
+        closed_issues,
+        open_pull_requests,
+        closed_pull_requests,
+    ))
+}
+
+// Async counterpart to [`issue_stats`], fetching all four counts
+// concurrently instead of one round trip at a time.
+//
+// [Github]: https://github.com/
+pub async fn issue_stats_async(
+    client: &GithubClient,
+    user: &str,
+    repo: &str,
+) -> Result<(u64, u64, u64, u64)> {
+    let (open_issues, closed_issues, open_pull_requests, closed_pull_requests) = try_join!(
+        count_for_async(client, user, repo, "issue", "open"),
+        count_for_async(client, user, repo, "issue", "closed"),
+        count_for_async(client, user, repo, "pr", "open"),
+        count_for_async(client, user, repo, "pr", "closed"),
+    )?;
+
+    Ok((
+        open_issues,
+        closed_issues,
+        open_pull_requests,
+        closed_pull_requests,
+    ))
+}
+
+fn count_for(
+    client: &GithubClient,
+    user: &str,
+    repo: &str,
+    kind: &str,
+    state: &str,
+) -> Result<u64> {
+    let url = count_url(client, user, repo, kind, state);
+    let response: Response = client.get(&url)?.json()?;
+    total_count(&response)
+}
+
+async fn count_for_async(
+    client: &GithubClient,
+    user: &str,
+    repo: &str,
+    kind: &str,
+    state: &str,
+) -> Result<u64> {
+    let url = count_url(client, user, repo, kind, state);
+    let response: Response = client.get_async(&url).await?.json().await?;
+    total_count(&response)
+}
+
+// Builds the search URL used to count issues/PRs of a given `kind` and
+// `state` for a repo.
+fn count_url(client: &GithubClient, user: &str, repo: &str, kind: &str, state: &str) -> String {
+    format!(
+        "{}{}?q=repo:{}/{}+type:{}+state:{}",
+        client.api_host(),
+        GITHUB_SEARCH_ISSUES_URL,
+        user,
+        repo,
+        kind,
+        state,
+    )
+}
+
+fn total_count(response: &Response) -> Result<u64> {
+    response["total_count"]
+        .as_u64()
+        .ok_or(r#""total_count" is not a u64"#.into())
+}
+
+// Fetches every issue/PR for a repo in the given `state`, following
+// Github's `Link: rel="next"` pagination header until it's exhausted.
+//
+// [Github]: https://github.com/
+pub fn list(
+    client: &GithubClient,
+    user: &str,
+    repo: &str,
+    state: State,
+) -> Result<Vec<Issue>> {
+    let mut next_url = Some(issues_url(client, user, repo, state));
+    let mut issues = Vec::new();
+
+    while let Some(url) = next_url {
+        let response = client.get(&url)?;
+        next_url = next_page_url(response.headers());
+        let page: Vec<Response> = response.json()?;
+        for item in &page {
+            issues.push(Issue::from_response(item)?);
+        }
+    }
+
+    Ok(issues)
+}
+
+// Async counterpart to [`list`]. Github's pagination is inherently
+// sequential (each page's URL comes from the previous page's headers), so
+// pages are still fetched one at a time, just without blocking the thread.
+//
+// [Github]: https://github.com/
+pub async fn list_async(
+    client: &GithubClient,
+    user: &str,
+    repo: &str,
+    state: State,
+) -> Result<Vec<Issue>> {
+    let mut next_url = Some(issues_url(client, user, repo, state));
+    let mut issues = Vec::new();
+
+    while let Some(url) = next_url {
+        let response = client.get_async(&url).await?;
+        next_url = next_page_url(response.headers());
+        let page: Vec<Response> = response.json().await?;
+        for item in &page {
+            issues.push(Issue::from_response(item)?);
+        }
+    }
+
+    Ok(issues)
+}
+
+// Builds the first page URL for listing issues/PRs in the given `state`.
+fn issues_url(client: &GithubClient, user: &str, repo: &str, state: State) -> String {
+    format!(
+        "{}/repos/{}/{}/issues?state={}&per_page=100",
+        client.api_host(),
+        user,
+        repo,
+        state.as_str(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::{HeaderMap, HeaderValue, LINK};
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parses_issue_from_response() {
+        let item = json!({
+            "number": 42,
+            "title": "Fix the thing",
+            "state": "open",
+            "created_at": "2020-01-01T00:00:00Z",
+            "updated_at": "2020-01-02T00:00:00Z",
+            "labels": [{"name": "bug"}, {"name": "help wanted"}],
+            "pull_request": {},
+        });
+
+        let issue = Issue::from_response(&item).unwrap();
+
+        assert_eq!(42, issue.number());
+        assert_eq!("Fix the thing", issue.title());
+        assert_eq!("open", issue.state());
+        assert_eq!("2020-01-01T00:00:00Z", issue.created_at());
+        assert_eq!("2020-01-02T00:00:00Z", issue.updated_at());
+        assert_eq!(
+            vec![String::from("bug"), String::from("help wanted")],
+            issue.labels().to_vec(),
+        );
+        assert!(issue.is_pull_request());
+    }
+
+    #[test]
+    fn parses_issue_that_is_not_a_pull_request() {
+        let item = json!({
+            "number": 1,
+            "title": "A plain issue",
+            "state": "closed",
+            "created_at": "2020-01-01T00:00:00Z",
+            "updated_at": "2020-01-02T00:00:00Z",
+            "labels": [],
+            "pull_request": null,
+        });
+
+        let issue = Issue::from_response(&item).unwrap();
+
+        assert!(!issue.is_pull_request());
+    }
+
+    #[test]
+    fn finds_next_page_url_from_link_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(
+                r#"<https://api.github.com/repos/o/r/issues?page=2>; rel="next", <https://api.github.com/repos/o/r/issues?page=5>; rel="last""#,
+            ),
+        );
+
+        assert_eq!(
+            Some(String::from("https://api.github.com/repos/o/r/issues?page=2")),
+            next_page_url(&headers),
+        );
+    }
+
+    #[test]
+    fn no_next_page_url_when_link_header_is_missing() {
+        assert_eq!(None, next_page_url(&HeaderMap::new()));
+    }
+}
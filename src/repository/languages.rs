@@ -0,0 +1,176 @@
+use crate::{GithubClient, JsonMap, Result};
+
+// Canonical per-language colors, as shown in Github's repository language
+// bar. Pulled from the `github-linguist` `languages.yml` color list.
+//
+// [Github]: https://github.com/
+const LANGUAGE_COLORS: &[(&str, &str)] = &[
+    ("Rust", "#dea584"),
+    ("JavaScript", "#f1e05a"),
+    ("TypeScript", "#3178c6"),
+    ("Python", "#3572A5"),
+    ("Go", "#00ADD8"),
+    ("Java", "#b07219"),
+    ("C", "#555555"),
+    ("C++", "#f34b7d"),
+    ("C#", "#178600"),
+    ("Ruby", "#701516"),
+    ("PHP", "#4F5D95"),
+    ("HTML", "#e34c26"),
+    ("CSS", "#563d7c"),
+    ("Shell", "#89e051"),
+    ("Swift", "#F05138"),
+    ("Kotlin", "#A97BFF"),
+    ("Objective-C", "#438eff"),
+    ("Scala", "#c22d40"),
+    ("Haskell", "#5e5086"),
+    ("Elixir", "#6e4a7e"),
+    ("Clojure", "#db5855"),
+    ("Lua", "#000080"),
+    ("Dart", "#00B4AB"),
+    ("R", "#198CE7"),
+    ("Perl", "#0298c3"),
+    ("Vue", "#41b883"),
+    ("TeX", "#3D6117"),
+    ("Dockerfile", "#384d54"),
+    ("Makefile", "#427819"),
+    ("PowerShell", "#012456"),
+    ("Assembly", "#6E4C13"),
+];
+
+// The color shown for a language Github has no canonical color for.
+const DEFAULT_LANGUAGE_COLOR: &str = "#cccccc";
+
+fn color_for(language: &str) -> &'static str {
+    LANGUAGE_COLORS
+        .iter()
+        .find(|(name, _)| *name == language)
+        .map(|(_, color)| *color)
+        .unwrap_or(DEFAULT_LANGUAGE_COLOR)
+}
+
+/// A single language's share of a repository's code, with the color
+/// [Github] renders it with in a language breakdown bar.
+///
+/// [Github]: https://github.com/
+#[derive(Debug)]
+pub struct LanguageStat {
+    name: String,
+    bytes: u64,
+    percent: f64,
+    color: &'static str,
+}
+
+impl LanguageStat {
+    /// The language's name, e.g. `Rust`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The number of bytes of code written in this language.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    /// This language's share of the repository's code, as a percentage.
+    pub fn percent(&self) -> f64 {
+        self.percent
+    }
+
+    /// The hex color [Github] renders this language with.
+    ///
+    /// [Github]: https://github.com/
+    pub fn color(&self) -> &'static str {
+        self.color
+    }
+}
+
+/// Requests the language breakdown at `url` (a repo's `languages_url`).
+///
+/// [Github]: https://github.com/
+pub fn from_api_url(client: &GithubClient, url: &str) -> Result<JsonMap<u64>> {
+    let languages: JsonMap<u64> = client.get(url)?.json()?;
+    Ok(languages)
+}
+
+/// Async counterpart to [`from_api_url`].
+///
+/// [Github]: https://github.com/
+pub async fn from_api_url_async(client: &GithubClient, url: &str) -> Result<JsonMap<u64>> {
+    let languages: JsonMap<u64> = client.get_async(url).await?.json().await?;
+    Ok(languages)
+}
+
+/// Converts raw per-language byte counts into percentages of the total,
+/// sorted from most to least code, with each language's canonical
+/// [Github] color attached.
+///
+/// [Github]: https://github.com/
+pub fn stats(languages: &JsonMap<u64>) -> Vec<LanguageStat> {
+    let total: u64 = languages.values().sum();
+
+    let mut stats: Vec<LanguageStat> = languages
+        .iter()
+        .map(|(name, &bytes)| {
+            let percent = if total == 0 {
+                0.0
+            } else {
+                (bytes as f64 / total as f64) * 100.0
+            };
+
+            LanguageStat {
+                name: name.clone(),
+                bytes,
+                percent,
+                color: color_for(name),
+            }
+        })
+        .collect();
+
+    stats.sort_by_key(|s| std::cmp::Reverse(s.bytes));
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_percentages_sorted_by_bytes_with_known_colors() {
+        let mut languages = JsonMap::new();
+        languages.insert(String::from("Rust"), 75);
+        languages.insert(String::from("Python"), 25);
+
+        let stats = stats(&languages);
+
+        assert_eq!("Rust", stats[0].name());
+        assert_eq!(75, stats[0].bytes());
+        assert_eq!(75.0, stats[0].percent());
+        assert_eq!("#dea584", stats[0].color());
+
+        assert_eq!("Python", stats[1].name());
+        assert_eq!(25, stats[1].bytes());
+        assert_eq!(25.0, stats[1].percent());
+        assert_eq!("#3572A5", stats[1].color());
+    }
+
+    #[test]
+    fn falls_back_to_default_color_for_unknown_languages() {
+        let mut languages = JsonMap::new();
+        languages.insert(String::from("Brainfuck"), 10);
+
+        let stats = stats(&languages);
+
+        assert_eq!(DEFAULT_LANGUAGE_COLOR, stats[0].color());
+    }
+
+    #[test]
+    fn zero_total_yields_zero_percentages() {
+        let mut languages = JsonMap::new();
+        languages.insert(String::from("Rust"), 0);
+
+        let stats = stats(&languages);
+
+        assert_eq!(0.0, stats[0].percent());
+    }
+}
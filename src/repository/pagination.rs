@@ -0,0 +1,17 @@
+use reqwest::header::{HeaderMap, LINK};
+
+// Parses the `<url>; rel="next"` pagination link out of a Github `Link`
+// response header, if one is present.
+pub fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+    link.split(',').find_map(|entry| {
+        let mut parts = entry.split(';');
+        let url = parts.next()?.trim();
+        let is_next = parts.any(|param| param.trim() == r#"rel="next""#);
+        if is_next {
+            Some(url.trim_start_matches('<').trim_end_matches('>').to_string())
+        } else {
+            None
+        }
+    })
+}
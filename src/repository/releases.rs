@@ -0,0 +1,88 @@
+use crate::{GithubClient, Response, Result};
+
+/// Represents a [Github] release.
+///
+/// [Github]: https://github.com/
+#[derive(Debug)]
+pub struct Release {
+    tag_name: String,
+    name: Option<String>,
+    published_at: String,
+    prerelease: bool,
+}
+
+impl Release {
+    /// Fetches the latest release for a repo, if one exists.
+    pub fn latest(client: &GithubClient, user: &str, repo: &str) -> Result<Option<Self>> {
+        let response = client.get(&latest_release_url(client, user, repo))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        Self::from_response(response.json()?)
+    }
+
+    /// Async counterpart to [`latest`](Self::latest).
+    pub async fn latest_async(client: &GithubClient, user: &str, repo: &str) -> Result<Option<Self>> {
+        let response = client
+            .get_async(&latest_release_url(client, user, repo))
+            .await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        Self::from_response(response.json().await?)
+    }
+
+    fn from_response(release_data: Response) -> Result<Option<Self>> {
+        let tag_name = release_data["tag_name"]
+            .as_str()
+            .ok_or(r#""tag_name" is not a string"#)?
+            .to_string();
+        let name = release_data["name"].as_str().map(String::from);
+        let published_at = release_data["published_at"]
+            .as_str()
+            .ok_or(r#""published_at" is not a string"#)?
+            .to_string();
+        let prerelease = release_data["prerelease"]
+            .as_bool()
+            .ok_or(r#""prerelease" is not a bool"#)?;
+
+        Ok(Some(Release {
+            tag_name,
+            name,
+            published_at,
+            prerelease,
+        }))
+    }
+
+    /// The release's tag, e.g. `v1.0.0`.
+    pub fn tag_name(&self) -> &str {
+        &self.tag_name
+    }
+
+    /// The release's name, if it has one.
+    pub fn name(&self) -> &Option<String> {
+        &self.name
+    }
+
+    /// When the release was published.
+    pub fn published_at(&self) -> &str {
+        &self.published_at
+    }
+
+    /// Whether this release is marked as a prerelease.
+    pub fn prerelease(&self) -> bool {
+        self.prerelease
+    }
+}
+
+// Builds the URL for a repo's latest-release endpoint.
+fn latest_release_url(client: &GithubClient, user: &str, repo: &str) -> String {
+    format!(
+        "{}/repos/{}/{}/releases/latest",
+        client.api_host(),
+        user,
+        repo
+    )
+}
@@ -0,0 +1,3 @@
+pub use query::Query;
+
+mod query;
@@ -1,6 +1,25 @@
 use std::fmt;
 
-use crate::Repo;
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC};
+
+use crate::{GithubClient, Repo, Response, Result};
+
+// Characters a qualifier value (e.g. a label or author name) is allowed to
+// keep as-is. Everything else is percent-encoded so that a value containing
+// `&`, `+`, or `#` can't be mistaken for a query delimiter once it's joined
+// into the `q=` string.
+const QUALIFIER_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'/')
+    .remove(b':')
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.');
+
+// Percent-encodes a single qualifier value before it's joined into the `q=`
+// query string.
+fn encode(value: &str) -> String {
+    percent_encoding::utf8_percent_encode(value, QUALIFIER_VALUE).to_string()
+}
 
 #[derive(Default)]
 pub struct Query {
@@ -8,6 +27,12 @@ pub struct Query {
     is: Vec<String>,
     r#type: Vec<String>,
     state: Vec<String>,
+    label: Vec<String>,
+    author: Vec<String>,
+    assignee: Vec<String>,
+    r#in: Vec<String>,
+    sort: Option<String>,
+    order: Option<String>,
 }
 
 impl Query {
@@ -52,6 +77,103 @@ impl Query {
         self.r#type.push(String::from(statement));
         self
     }
+
+    /// *Adds* a `state` statement to the query.
+    ///
+    /// Results in `state:statement`.
+    pub fn state(mut self, statement: &str) -> Self {
+        self.state.push(String::from(statement));
+        self
+    }
+
+    /// *Adds* a `label` statement to the query.
+    ///
+    /// Results in `label:statement`.
+    pub fn label(mut self, statement: &str) -> Self {
+        self.label.push(String::from(statement));
+        self
+    }
+
+    /// *Adds* an `author` statement to the query.
+    ///
+    /// Results in `author:statement`.
+    pub fn author(mut self, statement: &str) -> Self {
+        self.author.push(String::from(statement));
+        self
+    }
+
+    /// *Adds* an `assignee` statement to the query.
+    ///
+    /// Results in `assignee:statement`.
+    pub fn assignee(mut self, statement: &str) -> Self {
+        self.assignee.push(String::from(statement));
+        self
+    }
+
+    /// *Adds* an `in` statement to the query.
+    ///
+    /// Results in `in:statement`.
+    ///
+    /// *Use `r#in` to escape the `in` keyword.
+    pub fn r#in(mut self, statement: &str) -> Self {
+        self.r#in.push(String::from(statement));
+        self
+    }
+
+    /// *Sets* the field results are sorted by, e.g. `created`, `updated`,
+    /// or `comments` for issues, and `stars`, `forks`, or `updated` for
+    /// repositories.
+    ///
+    /// Results in `&sort=field`.
+    pub fn sort(mut self, field: &str) -> Self {
+        self.sort = Some(String::from(field));
+        self
+    }
+
+    /// *Sets* the sort direction, `asc` or `desc`.
+    ///
+    /// Results in `&order=direction`.
+    pub fn order(mut self, direction: &str) -> Self {
+        self.order = Some(String::from(direction));
+        self
+    }
+
+    /// Runs this query against [Github]'s issue/pull request search
+    /// endpoint.
+    ///
+    /// [Github]: https://github.com/
+    pub fn search_issues(&self, client: &GithubClient) -> Result<IssueSearchResults> {
+        let url = format!("{}/search/issues?{}", client.api_host(), self);
+        let response: Response = client.get(&url)?.json()?;
+        IssueSearchResults::from_response(&response)
+    }
+
+    /// Async counterpart to [`search_issues`](Self::search_issues).
+    pub async fn search_issues_async(&self, client: &GithubClient) -> Result<IssueSearchResults> {
+        let url = format!("{}/search/issues?{}", client.api_host(), self);
+        let response: Response = client.get_async(&url).await?.json().await?;
+        IssueSearchResults::from_response(&response)
+    }
+
+    /// Runs this query against [Github]'s repository search endpoint.
+    ///
+    /// [Github]: https://github.com/
+    pub fn search_repositories(&self, client: &GithubClient) -> Result<RepositorySearchResults> {
+        let url = format!("{}/search/repositories?{}", client.api_host(), self);
+        let response: Response = client.get(&url)?.json()?;
+        RepositorySearchResults::from_response(&response)
+    }
+
+    /// Async counterpart to
+    /// [`search_repositories`](Self::search_repositories).
+    pub async fn search_repositories_async(
+        &self,
+        client: &GithubClient,
+    ) -> Result<RepositorySearchResults> {
+        let url = format!("{}/search/repositories?{}", client.api_host(), self);
+        let response: Response = client.get_async(&url).await?.json().await?;
+        RepositorySearchResults::from_response(&response)
+    }
 }
 
 impl fmt::Display for Query {
@@ -59,22 +181,42 @@ impl fmt::Display for Query {
         let queries = {
             let mut repo: Vec<String> = self.repo.iter()
                 .map(|s| {
-                    format!("repo:{}", s)
+                    format!("repo:{}", encode(s))
                 })
                 .collect();
             let mut is: Vec<String> = self.is.iter()
                 .map(|s| {
-                    format!("is:{}", s)
+                    format!("is:{}", encode(s))
                 })
                 .collect();
             let mut r#type: Vec<String> = self.r#type.iter()
                 .map(|s| {
-                    format!("type:{}", s)
+                    format!("type:{}", encode(s))
                 })
                 .collect();
             let mut state: Vec<String> = self.state.iter()
                 .map(|s| {
-                    format!("state:{}", s)
+                    format!("state:{}", encode(s))
+                })
+                .collect();
+            let mut label: Vec<String> = self.label.iter()
+                .map(|s| {
+                    format!("label:{}", encode(s))
+                })
+                .collect();
+            let mut author: Vec<String> = self.author.iter()
+                .map(|s| {
+                    format!("author:{}", encode(s))
+                })
+                .collect();
+            let mut assignee: Vec<String> = self.assignee.iter()
+                .map(|s| {
+                    format!("assignee:{}", encode(s))
+                })
+                .collect();
+            let mut r#in: Vec<String> = self.r#in.iter()
+                .map(|s| {
+                    format!("in:{}", encode(s))
                 })
                 .collect();
 
@@ -83,23 +225,234 @@ impl fmt::Display for Query {
                 + is.len()
                 + r#type.len()
                 + state.len()
+                + label.len()
+                + author.len()
+                + assignee.len()
+                + r#in.len()
             );
 
             queries.append(&mut repo);
             queries.append(&mut is);
             queries.append(&mut r#type);
             queries.append(&mut state);
+            queries.append(&mut label);
+            queries.append(&mut author);
+            queries.append(&mut assignee);
+            queries.append(&mut r#in);
             queries
         };
 
         let queries = queries.join("+");
 
-        write!(f, "q={}", queries)
+        write!(f, "q={}", queries)?;
+        if let Some(sort) = &self.sort {
+            write!(f, "&sort={}", encode(sort))?;
+        }
+        if let Some(order) = &self.order {
+            write!(f, "&order={}", encode(order))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single issue or pull request returned from [Github]'s search API.
+///
+/// [Github]: https://github.com/
+#[derive(Debug)]
+pub struct IssueSearchItem {
+    number: u64,
+    title: String,
+    state: String,
+    html_url: String,
+}
+
+impl IssueSearchItem {
+    fn from_response(item: &Response) -> Result<Self> {
+        let number = item["number"]
+            .as_u64()
+            .ok_or(r#""number" is not a u64"#)?;
+        let title = item["title"]
+            .as_str()
+            .ok_or(r#""title" is not a string"#)?
+            .to_string();
+        let state = item["state"]
+            .as_str()
+            .ok_or(r#""state" is not a string"#)?
+            .to_string();
+        let html_url = item["html_url"]
+            .as_str()
+            .ok_or(r#""html_url" is not a string"#)?
+            .to_string();
+
+        Ok(IssueSearchItem {
+            number,
+            title,
+            state,
+            html_url,
+        })
+    }
+
+    /// The issue or pull request number.
+    pub fn number(&self) -> u64 {
+        self.number
+    }
+
+    /// The issue or pull request title.
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The issue or pull request's state, e.g. `open` or `closed`.
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// A link to the issue or pull request on [Github].
+    ///
+    /// [Github]: https://github.com/
+    pub fn html_url(&self) -> &str {
+        &self.html_url
+    }
+}
+
+/// The result of running a [Query] against [Github]'s issue/pull request
+/// search endpoint.
+///
+/// [Github]: https://github.com/
+#[derive(Debug)]
+pub struct IssueSearchResults {
+    total_count: u64,
+    items: Vec<IssueSearchItem>,
+}
+
+impl IssueSearchResults {
+    fn from_response(response: &Response) -> Result<Self> {
+        let total_count = response["total_count"]
+            .as_u64()
+            .ok_or(r#""total_count" is not a u64"#)?;
+        let items = response["items"]
+            .as_array()
+            .ok_or(r#""items" is not an array"#)?
+            .iter()
+            .map(IssueSearchItem::from_response)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(IssueSearchResults { total_count, items })
+    }
+
+    /// The total number of issues/pull requests matching the query, across
+    /// all pages.
+    pub fn total_count(&self) -> u64 {
+        self.total_count
+    }
+
+    /// The issues/pull requests on this page of results.
+    pub fn items(&self) -> &[IssueSearchItem] {
+        &self.items
+    }
+}
+
+/// A single repository returned from [Github]'s search API.
+///
+/// [Github]: https://github.com/
+#[derive(Debug)]
+pub struct RepositorySearchItem {
+    full_name: String,
+    stars: u64,
+    forks: u64,
+    html_url: String,
+}
+
+impl RepositorySearchItem {
+    fn from_response(item: &Response) -> Result<Self> {
+        let full_name = item["full_name"]
+            .as_str()
+            .ok_or(r#""full_name" is not a string"#)?
+            .to_string();
+        let stars = item["stargazers_count"]
+            .as_u64()
+            .ok_or(r#""stargazers_count" is not a u64"#)?;
+        let forks = item["forks"]
+            .as_u64()
+            .ok_or(r#""forks" is not a u64"#)?;
+        let html_url = item["html_url"]
+            .as_str()
+            .ok_or(r#""html_url" is not a string"#)?
+            .to_string();
+
+        Ok(RepositorySearchItem {
+            full_name,
+            stars,
+            forks,
+            html_url,
+        })
+    }
+
+    /// The repository's full name, e.g. `rust-lang/rust`.
+    pub fn full_name(&self) -> &str {
+        &self.full_name
+    }
+
+    /// The repository's star count.
+    pub fn stars(&self) -> u64 {
+        self.stars
+    }
+
+    /// The repository's fork count.
+    pub fn forks(&self) -> u64 {
+        self.forks
+    }
+
+    /// A link to the repository on [Github].
+    ///
+    /// [Github]: https://github.com/
+    pub fn html_url(&self) -> &str {
+        &self.html_url
+    }
+}
+
+/// The result of running a [Query] against [Github]'s repository search
+/// endpoint.
+///
+/// [Github]: https://github.com/
+#[derive(Debug)]
+pub struct RepositorySearchResults {
+    total_count: u64,
+    items: Vec<RepositorySearchItem>,
+}
+
+impl RepositorySearchResults {
+    fn from_response(response: &Response) -> Result<Self> {
+        let total_count = response["total_count"]
+            .as_u64()
+            .ok_or(r#""total_count" is not a u64"#)?;
+        let items = response["items"]
+            .as_array()
+            .ok_or(r#""items" is not an array"#)?
+            .iter()
+            .map(RepositorySearchItem::from_response)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(RepositorySearchResults { total_count, items })
+    }
+
+    /// The total number of repositories matching the query, across all
+    /// pages.
+    pub fn total_count(&self) -> u64 {
+        self.total_count
+    }
+
+    /// The repositories on this page of results.
+    pub fn items(&self) -> &[RepositorySearchItem] {
+        &self.items
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use serde_json::json;
+
     use super::*;
 
     #[test]
@@ -112,4 +465,93 @@ mod tests {
 
         assert_eq!("q=repo:rust-lang/rust+is:merged+type:pr", query);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn built_query_with_sort_and_order() {
+        let query = Query::new()
+            .repo("rust-lang", "rust")
+            .sort("created")
+            .order("desc")
+            .to_string();
+
+        assert_eq!(
+            "q=repo:rust-lang/rust&sort=created&order=desc",
+            query,
+        );
+    }
+
+    #[test]
+    fn built_query_with_label_author_assignee_and_in() {
+        let query = Query::new()
+            .label("bug")
+            .author("octocat")
+            .assignee("octocat")
+            .r#in("title")
+            .to_string();
+
+        assert_eq!(
+            "q=label:bug+author:octocat+assignee:octocat+in:title",
+            query,
+        );
+    }
+
+    #[test]
+    fn percent_encodes_qualifier_values() {
+        let query = Query::new()
+            .label("a&b")
+            .author("octo cat")
+            .to_string();
+
+        assert_eq!("q=label:a%26b+author:octo%20cat", query);
+    }
+
+    #[test]
+    fn parses_issue_search_results() {
+        let response = json!({
+            "total_count": 1,
+            "items": [{
+                "number": 42,
+                "title": "Fix the thing",
+                "state": "open",
+                "html_url": "https://github.com/rust-lang/rust/issues/42",
+            }],
+        });
+
+        let results = IssueSearchResults::from_response(&response).unwrap();
+
+        assert_eq!(1, results.total_count());
+        assert_eq!(1, results.items().len());
+        assert_eq!(42, results.items()[0].number());
+        assert_eq!("Fix the thing", results.items()[0].title());
+        assert_eq!("open", results.items()[0].state());
+        assert_eq!(
+            "https://github.com/rust-lang/rust/issues/42",
+            results.items()[0].html_url(),
+        );
+    }
+
+    #[test]
+    fn parses_repository_search_results() {
+        let response = json!({
+            "total_count": 1,
+            "items": [{
+                "full_name": "rust-lang/rust",
+                "stargazers_count": 90000,
+                "forks": 12000,
+                "html_url": "https://github.com/rust-lang/rust",
+            }],
+        });
+
+        let results = RepositorySearchResults::from_response(&response).unwrap();
+
+        assert_eq!(1, results.total_count());
+        assert_eq!(1, results.items().len());
+        assert_eq!("rust-lang/rust", results.items()[0].full_name());
+        assert_eq!(90000, results.items()[0].stars());
+        assert_eq!(12000, results.items()[0].forks());
+        assert_eq!(
+            "https://github.com/rust-lang/rust",
+            results.items()[0].html_url(),
+        );
+    }
+}